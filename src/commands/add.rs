@@ -12,6 +12,8 @@ use std::os::unix;
 #[cfg(windows)]
 use std::os::windows;
 
+use crate::utils::LinkManifest;
+
 pub fn execute(vault: &Path, conf_name: &str, files: &Option<Vec<PathBuf>>) -> Result<()> {
     let conf_path = vault.join(conf_name);
 
@@ -43,7 +45,7 @@ pub fn execute(vault: &Path, conf_name: &str, files: &Option<Vec<PathBuf>>) -> R
         return Ok(());
     };
 
-    move_and_symlink(files, &conf_path).with_context(|| {
+    move_and_symlink(vault, files, &conf_path).with_context(|| {
         format!(
             "Failed to move and symlink files for {}",
             conf_path.display()
@@ -96,7 +98,9 @@ fn move_recursive(src: &Path, dst: &Path) -> Result<()> {
     Ok(())
 }
 
-fn move_and_symlink(files: &[PathBuf], to: &Path) -> Result<()> {
+fn move_and_symlink(vault: &Path, files: &[PathBuf], to: &Path) -> Result<()> {
+    let mut manifest = LinkManifest::load(to).context("Failed to load link manifest")?;
+
     for f in files {
         let dest = to.join(f);
         if dest.exists() {
@@ -134,7 +138,17 @@ fn move_and_symlink(files: &[PathBuf], to: &Path) -> Result<()> {
             f.display().to_string().yellow(),
             dest.display().to_string().cyan()
         );
+
+        let vault_relative = dest
+            .strip_prefix(vault)
+            .context("Failed to compute vault-relative path")?
+            .to_path_buf();
+        manifest.record(vault_relative, f_can_file);
     }
 
+    manifest
+        .save(to)
+        .context("Failed to persist link manifest")?;
+
     Ok(())
 }
@@ -0,0 +1,49 @@
+use anyhow::{bail, Result};
+use git2::Repository;
+use owo_colors::OwoColorize;
+
+use crate::git::{GitRepository, LocalGitRepository};
+
+pub fn list(repo: Option<&Repository>) -> Result<()> {
+    let Some(repo) = repo else {
+        bail!("GIT repo not found");
+    };
+
+    let branches = LocalGitRepository::new(repo).branches()?;
+
+    if branches.is_empty() {
+        println!("  {} No branches found", "ℹ".blue());
+        return Ok(());
+    }
+
+    for branch in branches {
+        let marker = if branch.is_head { "*" } else { " " };
+        println!("  {} {}", marker.green(), branch.name.cyan());
+    }
+
+    Ok(())
+}
+
+pub fn switch(repo: Option<&Repository>, name: &str) -> Result<()> {
+    let Some(repo) = repo else {
+        bail!("GIT repo not found");
+    };
+
+    LocalGitRepository::new(repo).change_branch(name)?;
+
+    println!("  {} Switched to branch: {}", "✓".green(), name.cyan());
+
+    Ok(())
+}
+
+pub fn new(repo: Option<&Repository>, name: &str) -> Result<()> {
+    let Some(repo) = repo else {
+        bail!("GIT repo not found");
+    };
+
+    LocalGitRepository::new(repo).create_branch(name)?;
+
+    println!("  {} Created branch: {}", "✓".green(), name.cyan());
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use crate::config::Config;
+
+pub fn list(config: Option<&Config>) -> Result<()> {
+    let Some(config) = config else {
+        println!("  {} No rsdot.toml configuration found", "ℹ".blue());
+        return Ok(());
+    };
+
+    if config.packages.is_empty() {
+        println!("  {} No packages configured", "ℹ".blue());
+        return Ok(());
+    }
+
+    for (name, package) in &config.packages {
+        println!("  {} {}", "→".blue(), name.red().bold());
+
+        if package.targets.is_empty() {
+            println!("      {} (no targets)", "·".dimmed());
+        } else {
+            for target in &package.targets {
+                println!("      {} {}", "•".yellow(), target.display().cyan());
+            }
+        }
+    }
+
+    Ok(())
+}
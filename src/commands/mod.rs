@@ -0,0 +1,8 @@
+pub mod add;
+pub mod branch;
+pub mod config;
+pub mod pull;
+pub mod restore;
+pub mod status;
+pub mod sync;
+pub mod watch;
@@ -0,0 +1,88 @@
+use anyhow::{bail, Context, Result};
+use git2::{build::CheckoutBuilder, FetchOptions, RemoteCallbacks, Repository};
+use owo_colors::OwoColorize;
+use std::path::Path;
+
+use super::{restore, sync::credentials};
+use crate::config::{self, Config};
+
+pub fn execute(vault: &Path, repo: Option<&Repository>, config: Option<&Config>) -> Result<()> {
+    let Some(repo) = repo else {
+        bail!("GIT repo not found");
+    };
+
+    let branch_name = config::resolve_branch(repo, config)?;
+
+    println!("  {} Fetching from remote...", "→".blue());
+
+    let mut remote = config::resolve_remote(repo, config)?;
+
+    let mut callbacks = RemoteCallbacks::new();
+    callbacks.credentials(|url, username_from_url, allowed_types| {
+        credentials(repo, url, username_from_url, allowed_types)
+    });
+
+    let mut fetch_options = FetchOptions::new();
+    fetch_options.remote_callbacks(callbacks);
+
+    remote
+        .fetch(&[branch_name.as_str()], Some(&mut fetch_options), None)
+        .context("Failed to fetch from remote")?;
+
+    let fetch_head = repo
+        .find_reference("FETCH_HEAD")
+        .context("Failed to find FETCH_HEAD")?;
+    let fetch_commit = repo
+        .reference_to_annotated_commit(&fetch_head)
+        .context("Failed to resolve FETCH_HEAD")?;
+
+    let (analysis, _) = repo
+        .merge_analysis(&[&fetch_commit])
+        .context("Failed to analyze merge")?;
+
+    if analysis.is_up_to_date() {
+        println!("  {} Already up to date", "ℹ".blue());
+        return Ok(());
+    }
+
+    if !analysis.is_fast_forward() {
+        bail!(
+            "Cannot fast-forward '{}': a real merge is required. Resolve it manually",
+            branch_name
+        );
+    }
+
+    println!(
+        "  {} Fast-forwarding {}...",
+        "→".blue(),
+        branch_name.cyan()
+    );
+
+    let refname = format!("refs/heads/{}", branch_name);
+    let mut reference = repo
+        .find_reference(&refname)
+        .with_context(|| format!("Failed to find {}", refname))?;
+
+    reference
+        .set_target(fetch_commit.id(), "Fast-forward")
+        .with_context(|| format!("Failed to update {}", refname))?;
+
+    repo.set_head(&refname)
+        .with_context(|| format!("Failed to set HEAD to {}", refname))?;
+
+    let mut checkout = CheckoutBuilder::new();
+    checkout.force();
+
+    repo.checkout_head(Some(&mut checkout))
+        .context("Failed to checkout updated HEAD")?;
+
+    println!(
+        "  {} Fast-forwarded to {}",
+        "✓".green(),
+        fetch_commit.id().to_string()[..7].to_string().yellow()
+    );
+
+    restore::execute(vault, &None).context("Failed to relink configurations after pull")?;
+
+    Ok(())
+}
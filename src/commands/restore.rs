@@ -0,0 +1,258 @@
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use std::{fs, path::Path, path::PathBuf};
+
+#[cfg(unix)]
+use std::os::unix;
+
+#[cfg(windows)]
+use std::os::windows;
+
+use crate::utils::LinkManifest;
+
+pub fn execute(vault: &Path, conf_name: &Option<String>) -> Result<()> {
+    let confs = match conf_name {
+        Some(name) => vec![(name.clone(), vault.join(name))],
+        None => collect_confs(vault)?,
+    };
+
+    if confs.is_empty() {
+        println!("  {} No configurations found", "ℹ".blue());
+        return Ok(());
+    }
+
+    for (name, conf_path) in confs {
+        if !conf_path.is_dir() {
+            println!(
+                "  {} '{}' configuration does not exist. Skipping",
+                "⚠".yellow(),
+                name
+            );
+            continue;
+        }
+
+        restore_conf(vault, &name, &conf_path)?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn collect_confs(vault: &Path) -> Result<Vec<(String, PathBuf)>> {
+    let mut confs = fs::read_dir(vault)
+        .with_context(|| format!("Failed to read {}", vault.display()))?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            let file_name = path.file_name()?.to_str()?;
+
+            if path.is_dir() && !file_name.starts_with('.') {
+                Some((file_name.to_string(), path))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<(String, PathBuf)>>();
+
+    confs.sort_by(|a, b| a.0.cmp(&b.0));
+
+    Ok(confs)
+}
+
+fn restore_conf(vault: &Path, name: &str, conf_path: &Path) -> Result<()> {
+    println!("  {} {}", "→".blue(), name.red().bold());
+
+    let manifest = LinkManifest::load(conf_path)
+        .with_context(|| format!("Failed to load link manifest for {}", name))?;
+
+    if manifest.links.is_empty() {
+        println!("      {} (no recorded links)", "·".dimmed());
+        println!();
+        return Ok(());
+    }
+
+    for entry in &manifest.links {
+        let vault_file = vault.join(&entry.vault_path);
+        link_one(&vault_file, &entry.target).with_context(|| {
+            format!(
+                "Failed to restore link for {}",
+                entry.target.display()
+            )
+        })?;
+    }
+
+    println!();
+
+    Ok(())
+}
+
+fn link_one(vault_file: &Path, target: &Path) -> Result<()> {
+    if !vault_file.exists() {
+        println!(
+            "      {} {} is missing from the vault. Skipping",
+            "⚠".yellow(),
+            vault_file.display().to_string().cyan()
+        );
+        return Ok(());
+    }
+
+    if let Ok(existing) = fs::read_link(target) {
+        if existing == vault_file {
+            println!(
+                "      {} {} (already linked)",
+                "·".dimmed(),
+                target.display()
+            );
+        } else {
+            println!(
+                "      {} {} points elsewhere ({}). Skipping",
+                "⚠".yellow(),
+                target.display().to_string().cyan(),
+                existing.display()
+            );
+        }
+        return Ok(());
+    }
+
+    if target.exists() {
+        println!(
+            "      {} {} already exists and is not a symlink. Skipping",
+            "⚠".yellow(),
+            target.display().to_string().cyan()
+        );
+        return Ok(());
+    }
+
+    create_symlink(vault_file, target)?;
+
+    println!(
+        "      {} Linked: {} → {}",
+        "✓".green(),
+        target.display().to_string().yellow(),
+        vault_file.display().to_string().cyan()
+    );
+
+    Ok(())
+}
+
+fn create_symlink(vault_file: &Path, target: &Path) -> Result<()> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+
+    #[cfg(unix)]
+    unix::fs::symlink(vault_file, target).with_context(|| {
+        format!(
+            "Error when creating the symlink from {} to {}",
+            vault_file.display(),
+            target.display()
+        )
+    })?;
+
+    #[cfg(windows)]
+    windows::fs::symlink_file(vault_file, target)
+        .context("Failed to create symlink on Windows")?;
+
+    Ok(())
+}
+
+/// Repairs symlinks that `status` reported as drifted: missing, or pointing
+/// somewhere other than the vault. A target replaced by a real file is
+/// reported and left in place, not overwritten.
+pub fn relink(vault: &Path, conf_name: &Option<String>) -> Result<()> {
+    let confs = match conf_name {
+        Some(name) => vec![(name.clone(), vault.join(name))],
+        None => collect_confs(vault)?,
+    };
+
+    if confs.is_empty() {
+        println!("  {} No configurations found", "ℹ".blue());
+        return Ok(());
+    }
+
+    for (name, conf_path) in confs {
+        if !conf_path.is_dir() {
+            println!(
+                "  {} '{}' configuration does not exist. Skipping",
+                "⚠".yellow(),
+                name
+            );
+            continue;
+        }
+
+        relink_conf(vault, &name, &conf_path)?;
+    }
+
+    Ok(())
+}
+
+fn relink_conf(vault: &Path, name: &str, conf_path: &Path) -> Result<()> {
+    println!("  {} {}", "→".blue(), name.red().bold());
+
+    let manifest = LinkManifest::load(conf_path)
+        .with_context(|| format!("Failed to load link manifest for {}", name))?;
+
+    if manifest.links.is_empty() {
+        println!("      {} (no recorded links)", "·".dimmed());
+        println!();
+        return Ok(());
+    }
+
+    for entry in &manifest.links {
+        let vault_file = vault.join(&entry.vault_path);
+        repair_link(&vault_file, &entry.target).with_context(|| {
+            format!("Failed to relink {}", entry.target.display())
+        })?;
+    }
+
+    println!();
+
+    Ok(())
+}
+
+fn repair_link(vault_file: &Path, target: &Path) -> Result<()> {
+    if !vault_file.exists() {
+        println!(
+            "      {} {} is missing from the vault. Skipping",
+            "⚠".yellow(),
+            vault_file.display().to_string().cyan()
+        );
+        return Ok(());
+    }
+
+    match fs::symlink_metadata(target) {
+        Ok(metadata) if metadata.file_type().is_symlink() => {
+            if fs::read_link(target).ok().as_deref() == Some(vault_file) {
+                println!(
+                    "      {} {} (already linked)",
+                    "·".dimmed(),
+                    target.display()
+                );
+                return Ok(());
+            }
+
+            fs::remove_file(target)
+                .with_context(|| format!("Failed to remove stale symlink {}", target.display()))?;
+        }
+        Ok(_) => {
+            println!(
+                "      {} {} was replaced by a real file. Skipping to avoid data loss",
+                "⚠".yellow(),
+                target.display().to_string().cyan()
+            );
+            return Ok(());
+        }
+        Err(_) => {}
+    }
+
+    create_symlink(vault_file, target)?;
+
+    println!(
+        "      {} Relinked: {} → {}",
+        "✓".green(),
+        target.display().to_string().yellow(),
+        vault_file.display().to_string().cyan()
+    );
+
+    Ok(())
+}
@@ -1,9 +1,11 @@
 use anyhow::{Context, Result};
-use git2::{Repository, Status};
+use git2::{BranchType, Repository, Status};
 use owo_colors::OwoColorize;
 use std::{fs, path::Path};
 use walkdir::WalkDir;
 
+use crate::utils::{LinkManifest, LINKS_MANIFEST};
+
 pub struct FileStatus(pub(crate) PathBuf, pub(crate) Option<Status>);
 
 use std::path::PathBuf;
@@ -28,6 +30,10 @@ pub fn execute(vault: &Path, repo: Option<&Repository>) -> Result<()> {
                 println!("  {} Current branch: {}", "→".blue(), branch_name.cyan());
             }
         }
+
+        if let Some(summary) = branch_summary(repo)? {
+            println!("  {} {}", "→".blue(), summary);
+        }
     }
     println!();
 
@@ -58,6 +64,7 @@ pub fn execute(vault: &Path, repo: Option<&Repository>) -> Result<()> {
 
         let files: Vec<FileStatus> = WalkDir::new(&conf_path)
             .into_iter()
+            .filter_entry(|entry| !is_manifest_dir(entry))
             .filter_map(|entry| entry.ok())
             .filter_map(|entry| {
                 let path = entry.path();
@@ -83,12 +90,161 @@ pub fn execute(vault: &Path, repo: Option<&Repository>) -> Result<()> {
             }
         }
 
+        print_drift(vault, &conf_path)
+            .with_context(|| format!("Failed to check link drift for {}", conf_path.display()))?;
+
         println!();
     }
 
     Ok(())
 }
 
+/// Whether `entry` is the `.rsdot` manifest directory, which stores our own
+/// bookkeeping rather than a tracked dotfile and should be hidden from
+/// per-file status (unlike ordinary dotfiles such as `.bashrc`, which are
+/// exactly what this command is meant to report on).
+fn is_manifest_dir(entry: &walkdir::DirEntry) -> bool {
+    let Some(manifest_dir) = Path::new(LINKS_MANIFEST)
+        .components()
+        .next()
+        .and_then(|component| component.as_os_str().to_str())
+    else {
+        return false;
+    };
+
+    entry.file_name().to_str() == Some(manifest_dir)
+}
+
+/// How a deployed symlink has drifted from what the link manifest expects.
+#[derive(Debug, PartialEq, Eq)]
+enum DriftStatus {
+    /// The target no longer exists.
+    Unlinked,
+    /// The target exists but is a real file, not a symlink.
+    ReplacedByFile,
+    /// The target is a symlink, but not to the expected vault file.
+    WrongTarget,
+}
+
+impl DriftStatus {
+    fn marker(&self) -> String {
+        match self {
+            DriftStatus::Unlinked => " [unlinked]".red().to_string(),
+            DriftStatus::ReplacedByFile => " [replaced-by-file]".red().to_string(),
+            DriftStatus::WrongTarget => " [wrong-target]".red().to_string(),
+        }
+    }
+}
+
+/// Compares a deployed symlink `target` against the vault file it's
+/// supposed to point at, returning `None` when everything matches.
+fn drift_status(vault_file: &Path, target: &Path) -> Option<DriftStatus> {
+    match fs::symlink_metadata(target) {
+        Err(_) => Some(DriftStatus::Unlinked),
+        Ok(metadata) if !metadata.file_type().is_symlink() => Some(DriftStatus::ReplacedByFile),
+        Ok(_) => match fs::read_link(target) {
+            Ok(resolved) if resolved == vault_file => None,
+            _ => Some(DriftStatus::WrongTarget),
+        },
+    }
+}
+
+/// Cross-checks every link recorded for this configuration against the
+/// filesystem, reporting symlinks that were deleted, replaced by a real
+/// file, or repointed out-of-band.
+fn print_drift(vault: &Path, conf_path: &Path) -> Result<()> {
+    let manifest = LinkManifest::load(conf_path)
+        .with_context(|| format!("Failed to load link manifest for {}", conf_path.display()))?;
+
+    for entry in &manifest.links {
+        let vault_file = vault.join(&entry.vault_path);
+        let target = &entry.target;
+
+        if let Some(status) = drift_status(&vault_file, target) {
+            println!(
+                "      {} {}{}",
+                "•".yellow(),
+                target.display(),
+                status.marker()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds a compact summary line combining upstream tracking, stash count
+/// and conflict count, e.g. `⇡2 $1 ✗3`.
+fn branch_summary(repo: &Repository) -> Result<Option<String>> {
+    let mut parts = Vec::new();
+
+    if let Some(tracking) = tracking_indicator(repo) {
+        parts.push(tracking);
+    }
+
+    let stash_count = count_stashes(repo)?;
+    if stash_count > 0 {
+        parts.push(format!("${}", stash_count).magenta().to_string());
+    }
+
+    let conflict_count = count_conflicts(repo)?;
+    if conflict_count > 0 {
+        parts.push(format!("✗{}", conflict_count).red().to_string());
+    }
+
+    if parts.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(parts.join(" ")))
+    }
+}
+
+fn tracking_indicator(repo: &Repository) -> Option<String> {
+    let head = repo.head().ok()?;
+    let branch_name = head.shorthand()?;
+    let local_branch = repo.find_branch(branch_name, BranchType::Local).ok()?;
+    let upstream = local_branch.upstream().ok()?;
+
+    let local_oid = head.target()?;
+    let upstream_oid = upstream.get().target()?;
+
+    let (ahead, behind) = repo.graph_ahead_behind(local_oid, upstream_oid).ok()?;
+
+    let indicator = match (ahead, behind) {
+        (0, 0) => "≡".to_string(),
+        (ahead, 0) => format!("⇡{}", ahead),
+        (0, behind) => format!("⇣{}", behind),
+        (_, _) => "⇕".to_string(),
+    };
+
+    Some(indicator.blue().to_string())
+}
+
+fn count_stashes(repo: &Repository) -> Result<usize> {
+    let mut repo = Repository::open(repo.path())
+        .context("Failed to reopen repository for stash inspection")?;
+
+    let mut count = 0;
+    repo.stash_foreach(|_, _, _| {
+        count += 1;
+        true
+    })
+    .context("Failed to enumerate stashes")?;
+
+    Ok(count)
+}
+
+fn count_conflicts(repo: &Repository) -> Result<usize> {
+    let statuses = repo
+        .statuses(None)
+        .context("Failed to get repository status")?;
+
+    Ok(statuses
+        .iter()
+        .filter(|entry| entry.status().contains(Status::CONFLICTED))
+        .count())
+}
+
 fn print_status(file_status: FileStatus, conf_path: &Path) -> Result<()> {
     let (file_pathbuf, status) = (file_status.0, file_status.1);
 
@@ -132,6 +288,14 @@ fn print_status(file_status: FileStatus, conf_path: &Path) -> Result<()> {
         Some(Status::WT_DELETED) => {
             println!("      {} {}{}", "•".yellow(), file_name, " [deleted]".red())
         }
+        Some(Status::CONFLICTED) => {
+            println!(
+                "      {} {}{}",
+                "•".yellow(),
+                file_name,
+                " [conflict]".red().bold()
+            )
+        }
         Some(Status::IGNORED) => {
             println!(
                 "      {} {}{}",
@@ -150,3 +314,69 @@ fn print_status(file_status: FileStatus, conf_path: &Path) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+    use std::os::unix::fs as unix_fs;
+
+    #[test]
+    fn drift_status_detects_unlinked() {
+        let dir = temp_dir("unlinked");
+        let vault_file = dir.join("vault_file");
+        fs::write(&vault_file, b"content").unwrap();
+        let target = dir.join("target");
+
+        assert_eq!(drift_status(&vault_file, &target), Some(DriftStatus::Unlinked));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn drift_status_detects_replaced_by_file() {
+        let dir = temp_dir("replaced-by-file");
+        let vault_file = dir.join("vault_file");
+        fs::write(&vault_file, b"content").unwrap();
+        let target = dir.join("target");
+        fs::write(&target, b"real file").unwrap();
+
+        assert_eq!(
+            drift_status(&vault_file, &target),
+            Some(DriftStatus::ReplacedByFile)
+        );
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn drift_status_detects_wrong_target() {
+        let dir = temp_dir("wrong-target");
+        let vault_file = dir.join("vault_file");
+        fs::write(&vault_file, b"content").unwrap();
+        let other_file = dir.join("other_file");
+        fs::write(&other_file, b"other content").unwrap();
+        let target = dir.join("target");
+        unix_fs::symlink(&other_file, &target).unwrap();
+
+        assert_eq!(
+            drift_status(&vault_file, &target),
+            Some(DriftStatus::WrongTarget)
+        );
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn drift_status_none_when_linked_correctly() {
+        let dir = temp_dir("ok");
+        let vault_file = dir.join("vault_file");
+        fs::write(&vault_file, b"content").unwrap();
+        let target = dir.join("target");
+        unix_fs::symlink(&vault_file, &target).unwrap();
+
+        assert_eq!(drift_status(&vault_file, &target), None);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -1,8 +1,13 @@
-use anyhow::{anyhow, bail, Context, Result};
-use git2::{IndexAddOption, Repository};
+use anyhow::{bail, Context, Result};
+use git2::{
+    Cred, CredentialType, IndexAddOption, PushOptions, RemoteCallbacks, Repository, Signature,
+};
 use owo_colors::OwoColorize;
+use std::{cell::RefCell, rc::Rc};
 
-pub fn execute(repo: Option<&Repository>, push: bool) -> Result<()> {
+use crate::config::{self, Config};
+
+pub fn execute(repo: Option<&Repository>, push: bool, config: Option<&Config>) -> Result<()> {
     let Some(repo) = repo else {
         bail!("GIT repo not found");
     };
@@ -26,7 +31,11 @@ pub fn execute(repo: Option<&Repository>, push: bool) -> Result<()> {
     let tree_id = index.write_tree().context("Failed to write git tree")?;
     let tree = repo.find_tree(tree_id).context("Failed to find git tree")?;
 
-    let signature = repo.signature().context("Failed to get git signature")?;
+    let signature = match config.and_then(|config| config.signature.as_ref()) {
+        Some(signature) => Signature::now(&signature.name, &signature.email)
+            .context("Failed to build git signature from config")?,
+        None => repo.signature().context("Failed to get git signature")?,
+    };
     let head = repo
         .head()
         .context("Failed to get HEAD reference")?
@@ -56,23 +65,80 @@ pub fn execute(repo: Option<&Repository>, push: bool) -> Result<()> {
     if push {
         println!("  {} Pushing to remote...", "→".blue());
 
-        let mut remote = repo
-            .find_remote("origin")
-            .context("Remote 'origin' not found")?;
+        let mut remote = config::resolve_remote(repo, config)?;
+        let branch_name = config::resolve_branch(repo, config)?;
+
+        let mut callbacks = RemoteCallbacks::new();
+        callbacks.credentials(|url, username_from_url, allowed_types| {
+            credentials(repo, url, username_from_url, allowed_types)
+        });
+
+        let push_failed = Rc::new(RefCell::new(None));
+        let push_failed_handle = Rc::clone(&push_failed);
+        callbacks.push_update_reference(move |refname, status| {
+            if let Some(message) = status {
+                *push_failed_handle.borrow_mut() = Some(format!("{}: {}", refname, message));
+            }
+            Ok(())
+        });
 
-        let head = repo
-            .head()
-            .context("Failed to get HEAD reference for push")?;
-        let branch_name = head
-            .shorthand()
-            .ok_or_else(|| anyhow!("Cannot determine current branch"))?;
+        let mut push_options = PushOptions::new();
+        push_options.remote_callbacks(callbacks);
 
         remote
-            .push(&[&format!("refs/heads/{}", branch_name)], None)
+            .push(
+                &[&format!("refs/heads/{}", branch_name)],
+                Some(&mut push_options),
+            )
             .context("Failed to push to remote")?;
 
+        if let Some(message) = push_failed.borrow().as_ref() {
+            bail!("Remote rejected the push: {}", message);
+        }
+
         println!("  {} Pushed to remote", "✓".green());
     }
 
     Ok(())
 }
+
+pub(crate) fn credentials(
+    repo: &Repository,
+    url: &str,
+    username_from_url: Option<&str>,
+    allowed_types: CredentialType,
+) -> std::result::Result<Cred, git2::Error> {
+    if allowed_types.contains(CredentialType::SSH_KEY) {
+        let username = username_from_url.unwrap_or("git");
+
+        if let Ok(cred) = Cred::ssh_key_from_agent(username) {
+            return Ok(cred);
+        }
+
+        if let Some(home) = dirs_home() {
+            let private_key = home.join(".ssh/id_ed25519");
+            let private_key = if private_key.exists() {
+                private_key
+            } else {
+                home.join(".ssh/id_rsa")
+            };
+
+            if private_key.exists() {
+                return Cred::ssh_key(username, None, &private_key, None);
+            }
+        }
+    }
+
+    if allowed_types.contains(CredentialType::USER_PASS_PLAINTEXT) {
+        let config = repo.config()?;
+        return Cred::credential_helper(&config, url, username_from_url);
+    }
+
+    Err(git2::Error::from_str(
+        "No usable SSH credentials found (tried ssh-agent and ~/.ssh/id_ed25519, ~/.ssh/id_rsa)",
+    ))
+}
+
+fn dirs_home() -> Option<std::path::PathBuf> {
+    std::env::var_os("HOME").map(std::path::PathBuf::from)
+}
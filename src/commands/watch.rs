@@ -0,0 +1,133 @@
+use anyhow::{bail, Context, Result};
+use git2::Repository;
+use notify::{Config as NotifyConfig, Event, RecommendedWatcher, RecursiveMode, Watcher};
+use owo_colors::OwoColorize;
+use std::{
+    fs,
+    path::Path,
+    sync::mpsc::{channel, RecvTimeoutError},
+    time::{Duration, Instant},
+};
+
+use super::{restore, sync};
+use crate::{config::Config, utils::LinkManifest};
+
+pub fn execute(
+    vault: &Path,
+    repo: Option<&Repository>,
+    config: Option<&Config>,
+    push: bool,
+    debounce_ms: u64,
+) -> Result<()> {
+    let Some(repo) = repo else {
+        bail!("GIT repo not found");
+    };
+
+    let (tx, rx) = channel::<notify::Result<Event>>();
+
+    let mut watcher = RecommendedWatcher::new(tx, NotifyConfig::default())
+        .context("Failed to create filesystem watcher")?;
+
+    watch_vault(vault, &mut watcher).with_context(|| format!("Failed to watch {}", vault.display()))?;
+
+    watch_linked_sources(vault, &mut watcher)
+        .context("Failed to watch linked source files")?;
+
+    println!(
+        "  {} Watching {} for changes (debounce: {}ms)...",
+        "→".blue(),
+        vault.display().cyan(),
+        debounce_ms
+    );
+
+    let debounce = Duration::from_millis(debounce_ms);
+    let mut pending = false;
+    let mut deadline: Option<Instant> = None;
+
+    loop {
+        let timeout = match deadline {
+            Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+            None => Duration::from_secs(60 * 60),
+        };
+
+        match rx.recv_timeout(timeout) {
+            Ok(Ok(event)) => {
+                for path in &event.paths {
+                    println!("  {} Detected change: {}", "·".dimmed(), path.display());
+                }
+                pending = true;
+                deadline = Some(Instant::now() + debounce);
+            }
+            Ok(Err(err)) => {
+                println!("  {} Watch error: {}", "⚠".yellow(), err);
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                if pending {
+                    pending = false;
+                    deadline = None;
+
+                    if let Err(err) = sync::execute(Some(repo), push, config) {
+                        println!("  {} Sync after detected changes failed: {}", "⚠".yellow(), err);
+                    }
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    Ok(())
+}
+
+/// Watches the vault root non-recursively, plus each of its non-hidden
+/// configuration directories recursively, so the repository's own `.git`
+/// directory is never watched — otherwise every auto-sync commit would
+/// re-arm the debounce on its own object/ref writes.
+fn watch_vault(vault: &Path, watcher: &mut RecommendedWatcher) -> Result<()> {
+    watcher
+        .watch(vault, RecursiveMode::NonRecursive)
+        .with_context(|| format!("Failed to watch {}", vault.display()))?;
+
+    for entry in
+        fs::read_dir(vault).with_context(|| format!("Failed to read {}", vault.display()))?
+    {
+        let entry = entry.context("Failed to read directory entry")?;
+        let path = entry.path();
+
+        let is_hidden = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with('.'));
+
+        if is_hidden || !path.is_dir() {
+            continue;
+        }
+
+        watcher
+            .watch(&path, RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch {}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+/// Watches the parent directory of every file a configuration was
+/// originally symlinked from, so edits to the deployed dotfiles (not just
+/// the vault copies) trigger a sync too.
+fn watch_linked_sources(vault: &Path, watcher: &mut RecommendedWatcher) -> Result<()> {
+    for (_, conf_path) in restore::collect_confs(vault)? {
+        let manifest = LinkManifest::load(&conf_path)
+            .with_context(|| format!("Failed to load link manifest for {}", conf_path.display()))?;
+
+        for entry in manifest.links {
+            let Some(parent) = entry.target.parent() else {
+                continue;
+            };
+
+            // Best effort: a target directory that no longer exists just
+            // means there is nothing to watch there yet.
+            let _ = watcher.watch(parent, RecursiveMode::NonRecursive);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use anyhow::{Context, Result};
+use git2::{Remote, Repository};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing at the vault, used when no `rsdot.toml` is found.
+pub const VAULT_DIR_ENV: &str = "VAULT_DIR";
+
+/// Name of the declarative configuration file.
+pub const CONFIG_FILE_NAME: &str = "rsdot.toml";
+
+#[derive(Debug, Deserialize)]
+pub struct Config {
+    pub vault: PathBuf,
+    #[serde(default)]
+    pub remote: Option<RemoteConfig>,
+    #[serde(default)]
+    pub signature: Option<SignatureConfig>,
+    #[serde(default)]
+    pub packages: BTreeMap<String, Package>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RemoteConfig {
+    pub url: Option<String>,
+    #[serde(default = "default_branch")]
+    pub branch: String,
+}
+
+fn default_branch() -> String {
+    "main".to_string()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SignatureConfig {
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Package {
+    /// Paths (relative to the configuration directory) linked by this package.
+    #[serde(default)]
+    pub targets: Vec<PathBuf>,
+}
+
+impl Config {
+    /// Looks for `rsdot.toml` first in the vault pointed at by `VAULT_DIR`
+    /// (if set), then in `$XDG_CONFIG_HOME/rsdot`.
+    pub fn find() -> Option<PathBuf> {
+        if let Ok(vault) = env::var(VAULT_DIR_ENV) {
+            let candidate = PathBuf::from(vault).join(CONFIG_FILE_NAME);
+            if candidate.is_file() {
+                return Some(candidate);
+            }
+        }
+
+        let config_home = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+        let candidate = config_home.join("rsdot").join(CONFIG_FILE_NAME);
+        candidate.is_file().then_some(candidate)
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+}
+
+/// Resolves the remote to push/pull against: the `[remote] url` configured
+/// in `rsdot.toml` when set, otherwise the repository's `origin`.
+pub fn resolve_remote<'repo>(repo: &'repo Repository, config: Option<&Config>) -> Result<Remote<'repo>> {
+    let url = config
+        .and_then(|config| config.remote.as_ref())
+        .and_then(|remote| remote.url.as_deref());
+
+    match url {
+        Some(url) => repo
+            .remote_anonymous(url)
+            .with_context(|| format!("Failed to use configured remote {}", url)),
+        None => repo.find_remote("origin").context("Remote 'origin' not found"),
+    }
+}
+
+/// Resolves the branch to push/pull: the `[remote] branch` configured in
+/// `rsdot.toml` (`main` when a `[remote]` table omits it), otherwise, when
+/// there is no `[remote]` table, the repository's current branch.
+pub fn resolve_branch(repo: &Repository, config: Option<&Config>) -> Result<String> {
+    if let Some(branch) = config
+        .and_then(|config| config.remote.as_ref())
+        .map(|remote| remote.branch.clone())
+    {
+        return Ok(branch);
+    }
+
+    repo.head()
+        .context("Failed to get HEAD reference")?
+        .shorthand()
+        .context("Cannot determine current branch")
+        .map(str::to_string)
+}
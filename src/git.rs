@@ -0,0 +1,93 @@
+use anyhow::{Context, Result};
+use git2::{build::CheckoutBuilder, BranchType, Repository};
+
+/// A local branch and the commit time of its tip, used to sort branches
+/// most-recent-first.
+pub struct BranchInfo {
+    pub name: String,
+    pub is_head: bool,
+    pub committed_at: i64,
+}
+
+/// Thin abstraction over the branch operations the CLI needs, so commands
+/// don't depend directly on `git2::Repository`'s lower-level API.
+pub trait GitRepository {
+    fn branches(&self) -> Result<Vec<BranchInfo>>;
+    fn change_branch(&self, name: &str) -> Result<()>;
+    fn create_branch(&self, name: &str) -> Result<()>;
+}
+
+pub struct LocalGitRepository<'repo> {
+    repo: &'repo Repository,
+}
+
+impl<'repo> LocalGitRepository<'repo> {
+    pub fn new(repo: &'repo Repository) -> Self {
+        Self { repo }
+    }
+}
+
+impl GitRepository for LocalGitRepository<'_> {
+    fn branches(&self) -> Result<Vec<BranchInfo>> {
+        let head_name = self
+            .repo
+            .head()
+            .ok()
+            .and_then(|head| head.shorthand().map(str::to_string));
+
+        let mut branches = self
+            .repo
+            .branches(Some(BranchType::Local))
+            .context("Failed to list local branches")?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|(branch, _)| {
+                let name = branch.name().ok()??.to_string();
+                let target = branch.get().target()?;
+                let committed_at = self.repo.find_commit(target).ok()?.time().seconds();
+                let is_head = head_name.as_deref() == Some(name.as_str());
+
+                Some(BranchInfo {
+                    name,
+                    is_head,
+                    committed_at,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        branches.sort_by_key(|branch| std::cmp::Reverse(branch.committed_at));
+
+        Ok(branches)
+    }
+
+    fn change_branch(&self, name: &str) -> Result<()> {
+        let refname = format!("refs/heads/{}", name);
+
+        self.repo
+            .set_head(&refname)
+            .with_context(|| format!("Failed to set HEAD to {}", refname))?;
+
+        let mut checkout = CheckoutBuilder::new();
+        checkout.force();
+
+        self.repo
+            .checkout_head(Some(&mut checkout))
+            .with_context(|| format!("Failed to checkout {}", name))?;
+
+        Ok(())
+    }
+
+    fn create_branch(&self, name: &str) -> Result<()> {
+        let head = self.repo.head().context("Failed to get HEAD reference")?;
+        let target = head.target().context("HEAD has no target commit")?;
+        let head_commit = self
+            .repo
+            .find_commit(target)
+            .context("Failed to find HEAD commit")?;
+
+        self.repo
+            .branch(name, &head_commit, false)
+            .with_context(|| format!("Failed to create branch {}", name))?;
+
+        Ok(())
+    }
+}
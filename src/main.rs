@@ -1,14 +1,17 @@
 mod commands;
+mod config;
+mod git;
+#[cfg(test)]
+mod test_support;
 mod utils;
 
 use anyhow::{bail, Result};
 use clap::{Parser, Subcommand};
+use config::Config;
 use git2::Repository;
-use std::{env, path::PathBuf};
+use std::path::PathBuf;
 use utils::validate_file;
 
-const VAULT_DIR_ENV: &str = "VAULT_DIR";
-
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Args {
@@ -34,28 +37,104 @@ enum Commands {
         /// push to remote
         push: bool,
     },
+    /// recreate symlinks from the vault on this machine
+    Restore {
+        /// config name, or all configurations when omitted
+        conf_name: Option<String>,
+    },
+    /// repair symlinks that have drifted from the vault
+    Relink {
+        /// config name, or all configurations when omitted
+        conf_name: Option<String>,
+    },
+    /// fetch, fast-forward and re-apply dotfiles
+    Pull,
+    /// inspect the rsdot.toml configuration
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+    /// list, switch or create branches
+    Branch {
+        #[command(subcommand)]
+        action: Option<BranchAction>,
+    },
+    /// watch the vault and linked files, auto-syncing on changes
+    Watch {
+        #[arg(short, long)]
+        /// push to remote after each sync
+        push: bool,
+        #[arg(long, default_value_t = 2000)]
+        /// debounce window, in milliseconds
+        debounce_ms: u64,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+enum ConfigAction {
+    /// list configured packages and their link targets
+    List,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+enum BranchAction {
+    /// switch to an existing branch
+    Switch {
+        /// branch name
+        name: String,
+    },
+    /// create a new branch from HEAD
+    New {
+        /// branch name
+        name: String,
+    },
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
 
-    let vault = match env::var(VAULT_DIR_ENV) {
-        Err(_) => bail!("{} must be set", VAULT_DIR_ENV),
-        Ok(vault) => {
-            let vault = PathBuf::from(vault);
-            if !vault.is_dir() {
-                bail!("{} is not a directory", VAULT_DIR_ENV)
-            }
-            vault
-        }
+    let config = Config::find().map(|path| Config::load(&path)).transpose()?;
+
+    let vault = match &config {
+        Some(config) => config.vault.clone(),
+        None => match std::env::var(config::VAULT_DIR_ENV) {
+            Err(_) => bail!(
+                "{} must be set, or a {} must be configured",
+                config::VAULT_DIR_ENV,
+                config::CONFIG_FILE_NAME
+            ),
+            Ok(vault) => PathBuf::from(vault),
+        },
     };
 
+    if !vault.is_dir() {
+        bail!("{} is not a directory", vault.display())
+    }
+
     let repo = Repository::open(&vault).ok();
 
     match &args.cmd {
         Commands::Status => commands::status::execute(&vault, repo.as_ref())?,
         Commands::Add { conf_name, files } => commands::add::execute(&vault, conf_name, files)?,
-        Commands::Sync { push } => commands::sync::execute(repo.as_ref(), *push)?,
+        Commands::Sync { push } => commands::sync::execute(repo.as_ref(), *push, config.as_ref())?,
+        Commands::Restore { conf_name } => commands::restore::execute(&vault, conf_name)?,
+        Commands::Relink { conf_name } => commands::restore::relink(&vault, conf_name)?,
+        Commands::Pull => commands::pull::execute(&vault, repo.as_ref(), config.as_ref())?,
+        Commands::Config { action } => match action {
+            ConfigAction::List => commands::config::list(config.as_ref())?,
+        },
+        Commands::Branch { action } => match action {
+            None => commands::branch::list(repo.as_ref())?,
+            Some(BranchAction::Switch { name }) => commands::branch::switch(repo.as_ref(), name)?,
+            Some(BranchAction::New { name }) => commands::branch::new(repo.as_ref(), name)?,
+        },
+        Commands::Watch { push, debounce_ms } => commands::watch::execute(
+            &vault,
+            repo.as_ref(),
+            config.as_ref(),
+            *push,
+            *debounce_ms,
+        )?,
     }
 
     Ok(())
@@ -0,0 +1,9 @@
+use std::{fs, path::PathBuf};
+
+/// Creates (and returns) a process-unique scratch directory under the OS
+/// temp dir, namespaced by `name` so tests in the same run don't collide.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rsdot-test-{}-{}", std::process::id(), name));
+    fs::create_dir_all(&dir).expect("create temp dir");
+    dir
+}
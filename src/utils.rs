@@ -1,5 +1,9 @@
-use anyhow::Result;
-use std::path::PathBuf;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 pub fn validate_file(file: &str) -> Result<PathBuf, String> {
     let path = PathBuf::from(file);
@@ -15,4 +19,119 @@ pub fn validate_file(file: &str) -> Result<PathBuf, String> {
     }
 
     Ok(path)
-}
\ No newline at end of file
+}
+
+/// Name of the manifest file (relative to a configuration directory) that
+/// maps vault-stored files back to the location they were symlinked from.
+///
+/// This is deliberately committed to the vault repo (not gitignored):
+/// `restore`/`pull` read it back on a fresh machine, so it has to travel
+/// with the vault, not stay local.
+pub const LINKS_MANIFEST: &str = ".rsdot/links.toml";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkEntry {
+    /// Path of the file inside the vault, relative to the vault root.
+    pub vault_path: PathBuf,
+    /// Canonical path the file was originally symlinked from.
+    pub target: PathBuf,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LinkManifest {
+    #[serde(default)]
+    pub links: Vec<LinkEntry>,
+}
+
+impl LinkManifest {
+    pub fn load(conf_path: &Path) -> Result<Self> {
+        let manifest_path = conf_path.join(LINKS_MANIFEST);
+
+        if !manifest_path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&manifest_path)
+            .with_context(|| format!("Failed to read {}", manifest_path.display()))?;
+
+        toml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", manifest_path.display()))
+    }
+
+    pub fn save(&self, conf_path: &Path) -> Result<()> {
+        let manifest_path = conf_path.join(LINKS_MANIFEST);
+
+        if let Some(parent) = manifest_path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+
+        let content =
+            toml::to_string_pretty(self).context("Failed to serialize link manifest")?;
+
+        fs::write(&manifest_path, content)
+            .with_context(|| format!("Failed to write {}", manifest_path.display()))
+    }
+
+    /// Records (or replaces) the mapping for `vault_path`.
+    pub fn record(&mut self, vault_path: PathBuf, target: PathBuf) {
+        self.links.retain(|entry| entry.vault_path != vault_path);
+        self.links.push(LinkEntry { vault_path, target });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+
+    #[test]
+    fn link_manifest_round_trips_through_toml() {
+        let dir = temp_dir("round-trip");
+
+        let mut manifest = LinkManifest::default();
+        manifest.record(PathBuf::from("zshrc"), PathBuf::from("/home/user/.zshrc"));
+        manifest.record(PathBuf::from("vimrc"), PathBuf::from("/home/user/.vimrc"));
+        manifest.save(&dir).unwrap();
+
+        let loaded = LinkManifest::load(&dir).unwrap();
+
+        assert_eq!(loaded.links.len(), 2);
+        assert!(loaded
+            .links
+            .iter()
+            .any(|entry| entry.vault_path == Path::new("zshrc")
+                && entry.target == Path::new("/home/user/.zshrc")));
+        assert!(loaded
+            .links
+            .iter()
+            .any(|entry| entry.vault_path == Path::new("vimrc")
+                && entry.target == Path::new("/home/user/.vimrc")));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn link_manifest_record_replaces_existing_entry_for_same_vault_path() {
+        let mut manifest = LinkManifest::default();
+        manifest.record(PathBuf::from("zshrc"), PathBuf::from("/home/user/.zshrc"));
+        manifest.record(
+            PathBuf::from("zshrc"),
+            PathBuf::from("/home/user/.zshrc.new"),
+        );
+
+        assert_eq!(manifest.links.len(), 1);
+        assert_eq!(manifest.links[0].target, PathBuf::from("/home/user/.zshrc.new"));
+    }
+
+    #[test]
+    fn link_manifest_load_missing_file_returns_empty_manifest() {
+        let dir = temp_dir("missing");
+
+        let manifest = LinkManifest::load(&dir).unwrap();
+
+        assert!(manifest.links.is_empty());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}